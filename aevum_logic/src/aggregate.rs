@@ -0,0 +1,361 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+//! # AevumDB Aggregation Pipeline
+//!
+//! `rust_find` covers filter + sort + projection + pagination, but some workloads need
+//! server-side reduction (grouping, totals, array flattening) so the host doesn't have to ship
+//! every document across the FFI boundary just to fold them in C++. This module layers a small
+//! MongoDB-style aggregation pipeline on top of the existing engine: an ordered list of stages,
+//! each consuming the previous stage's output document stream.
+//!
+//! ## Supported Stages
+//!
+//! | Stage | Description |
+//! |-------|-------------|
+//! | `$match` | Filters the stream using the same `predicate::matches` semantics as `rust_find`. |
+//! | `$project` | Reshapes each document via `engine::apply_projection`. |
+//! | `$sort` | Orders the stream; supports the same `$order`/`$collation` spec as `rust_find`. |
+//! | `$limit` | Truncates the stream to at most N documents. |
+//! | `$skip` | Drops the first N documents. |
+//! | `$group` | Buckets documents by an `_id` expression, reducing each bucket with accumulators. |
+//! | `$unwind` | Flattens an array field, emitting one output document per element. |
+//!
+//! `$match` deliberately reuses `predicate::matches` rather than re-implementing query
+//! evaluation, so `$gt`/`$regex`/`$and`/etc. behave identically inside a pipeline and in a plain
+//! `rust_find` call.
+
+use crate::engine;
+use crate::operators;
+use crate::predicate;
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+// ==================================================================================
+//  PUBLIC API
+// ==================================================================================
+
+/// Runs an aggregation pipeline over a dataset and serializes the resulting document stream.
+///
+/// Mirrors the "Deserialization-Process-Serialization" shape of `engine::find`: malformed
+/// input JSON degrades to an empty dataset/pipeline rather than propagating a parse error, and
+/// the final stream is serialized with the same fallback-to-`"[]"` behavior on failure.
+///
+/// # Arguments
+/// * `data_str` - The complete dataset (JSON array).
+/// * `pipeline_str` - An ordered JSON array of single-key stage objects, e.g.
+///   `[{"$match": {"age": {"$gt": 18}}}, {"$sort": {"age": -1}}]`.
+///
+/// # Returns
+/// A serialized JSON string representing the final result set.
+pub fn run(data_str: &str, pipeline_str: &str) -> String {
+    let data: Value = serde_json::from_str(data_str).unwrap_or(Value::Array(vec![]));
+    let pipeline: Value = serde_json::from_str(pipeline_str).unwrap_or(Value::Array(vec![]));
+
+    let mut stream: Vec<Value> = data.as_array().cloned().unwrap_or_default();
+
+    if let Some(stages) = pipeline.as_array() {
+        for stage in stages {
+            stream = apply_stage(stream, stage);
+        }
+    }
+
+    serde_json::to_string(&stream).unwrap_or_else(|_| "[]".to_string())
+}
+
+// ==================================================================================
+//  PRIVATE HELPERS
+// ==================================================================================
+
+/// Applies a single pipeline stage to the stream, dispatching on its lone key.
+///
+/// A malformed stage (not a single-key object, or an unrecognized key) is a safe no-op: the
+/// stream passes through unchanged rather than aborting the whole pipeline.
+fn apply_stage(stream: Vec<Value>, stage: &Value) -> Vec<Value> {
+    let Some(stage_obj) = stage.as_object() else {
+        return stream;
+    };
+
+    // A stage with more than one key (or none at all, e.g. `{}`) is malformed the same way a
+    // single unrecognized key is: pass the stream through unchanged rather than guessing which
+    // key the caller meant.
+    let Some((op, arg)) = (stage_obj.len() == 1).then(|| stage_obj.iter().next()).flatten() else {
+        return stream;
+    };
+
+    match op.as_str() {
+        "$match" => stream.into_iter().filter(|doc| predicate::matches(doc, arg)).collect(),
+        "$project" => stream.iter().map(|doc| engine::apply_projection(doc, arg)).collect(),
+        "$sort" => apply_sort(stream, arg),
+        "$limit" => apply_limit(stream, arg),
+        "$skip" => apply_skip(stream, arg),
+        "$group" => apply_group(stream, arg),
+        "$unwind" => apply_unwind(stream, arg),
+        _ => stream,
+    }
+}
+
+/// Orders the stream by one or more fields, identical in spirit to `engine::find`'s sort
+/// phase: a field's spec is either a plain `1`/`-1`, or the object form
+/// `{"$order": 1, "$collation": "ci"}` for case-insensitive ordering.
+fn apply_sort(mut stream: Vec<Value>, sort_spec: &Value) -> Vec<Value> {
+    let Some(sort_obj) = sort_spec.as_object() else {
+        return stream;
+    };
+    if sort_obj.is_empty() {
+        return stream;
+    }
+
+    stream.sort_by(|a, b| {
+        for (key, order) in sort_obj {
+            let val_a = a.get(key).unwrap_or(&Value::Null);
+            let val_b = b.get(key).unwrap_or(&Value::Null);
+            let (descending, case_insensitive) = engine::parse_sort_spec(order);
+            let cmp = if case_insensitive {
+                operators::compare_values_collated(val_a, val_b).then_with(|| operators::compare_values(val_a, val_b))
+            } else {
+                operators::compare_values(val_a, val_b)
+            };
+
+            if cmp != Ordering::Equal {
+                return if descending { cmp.reverse() } else { cmp };
+            }
+        }
+        Ordering::Equal
+    });
+
+    stream
+}
+
+/// Truncates the stream to at most `limit` documents. A non-numeric or negative limit is a
+/// no-op, consistent with `rust_find`'s "sanitize to zero" contract being the caller's job.
+fn apply_limit(mut stream: Vec<Value>, limit: &Value) -> Vec<Value> {
+    if let Some(n) = limit.as_u64() {
+        stream.truncate(n as usize);
+    }
+    stream
+}
+
+/// Drops the first `skip` documents from the stream.
+fn apply_skip(stream: Vec<Value>, skip: &Value) -> Vec<Value> {
+    match skip.as_u64() {
+        Some(n) => stream.into_iter().skip(n as usize).collect(),
+        None => stream,
+    }
+}
+
+/// Resolves a `$group` expression against a document: a `"$field"` string dereferences the
+/// named top-level field (missing fields resolve to `Null`, matching the rest of this engine's
+/// fail-safe field lookups); any other value is a literal, returned as-is.
+fn resolve_expr(doc: &Value, expr: &Value) -> Value {
+    match expr.as_str() {
+        Some(s) if s.starts_with('$') => doc.get(&s[1..]).cloned().unwrap_or(Value::Null),
+        _ => expr.clone(),
+    }
+}
+
+/// Buckets the stream by an `_id` expression and reduces each bucket with the requested
+/// accumulators, emitting one output document per distinct `_id`.
+///
+/// # Group Spec Shape
+/// ```json
+/// {"_id": "$role", "total": {"$sum": 1}, "avgAge": {"$avg": "$age"}}
+/// ```
+/// Every key other than `_id` is an output field whose single-key object selects an
+/// accumulator (`$sum`, `$avg`, `$min`, `$max`, `$count`, `$push`) and the expression fed to it.
+///
+/// Buckets are emitted in first-seen order (the order their `_id` value first appears in the
+/// input stream), mirroring the engine's general preference for stable, input-order-derived
+/// output over an arbitrary hash order.
+fn apply_group(stream: Vec<Value>, group_spec: &Value) -> Vec<Value> {
+    let Some(group_obj) = group_spec.as_object() else {
+        return stream;
+    };
+
+    let id_expr = group_obj.get("_id").unwrap_or(&Value::Null);
+    let specs: Vec<(&String, &str, &Value)> = group_obj
+        .iter()
+        .filter(|(key, _)| key.as_str() != "_id")
+        .filter_map(|(field, spec)| {
+            let spec_obj = spec.as_object()?;
+            let (op, expr) = spec_obj.iter().next()?;
+            Some((field, op.as_str(), expr))
+        })
+        .collect();
+
+    struct Bucket {
+        key: Value,
+        accs: Vec<Accumulator>,
+    }
+
+    let mut order: Vec<String> = Vec::new();
+    let mut buckets: HashMap<String, Bucket> = HashMap::new();
+
+    for doc in &stream {
+        let key = resolve_expr(doc, id_expr);
+        // Grouping needs a hashable/orderable key, but `Value` provides neither; the canonical
+        // JSON serialization of the key is used as a dedup handle instead (mirroring the
+        // `index` module's CanonKey approach, at a smaller scale that doesn't need a real hash).
+        let key_repr = serde_json::to_string(&key).unwrap_or_default();
+
+        let bucket = buckets.entry(key_repr.clone()).or_insert_with(|| {
+            order.push(key_repr.clone());
+            Bucket {
+                key: key.clone(),
+                accs: specs.iter().map(|(_, op, _)| Accumulator::new(op)).collect(),
+            }
+        });
+
+        for (acc, (_, _, expr)) in bucket.accs.iter_mut().zip(specs.iter()) {
+            acc.update(&resolve_expr(doc, expr));
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|key_repr| buckets.remove(&key_repr))
+        .map(|bucket| {
+            let mut obj = Map::new();
+            obj.insert("_id".to_string(), bucket.key);
+            for ((field, _, _), acc) in specs.iter().zip(bucket.accs.into_iter()) {
+                obj.insert((*field).clone(), acc.finalize());
+            }
+            Value::Object(obj)
+        })
+        .collect()
+}
+
+/// Running state for a single `$group` accumulator.
+enum Accumulator {
+    Sum(f64),
+    Avg { sum: f64, count: u64 },
+    Min(Option<Value>),
+    Max(Option<Value>),
+    Count(u64),
+    Push(Vec<Value>),
+}
+
+impl Accumulator {
+    /// Creates the zero/identity state for an accumulator operator.
+    ///
+    /// An unrecognized operator defaults to `$sum`'s behavior, since a typo'd accumulator
+    /// producing a numeric zero is easier to notice (and debug) than the field silently
+    /// disappearing from every output document.
+    fn new(op: &str) -> Self {
+        match op {
+            "$avg" => Accumulator::Avg { sum: 0.0, count: 0 },
+            "$min" => Accumulator::Min(None),
+            "$max" => Accumulator::Max(None),
+            "$count" => Accumulator::Count(0),
+            "$push" => Accumulator::Push(Vec::new()),
+            _ => Accumulator::Sum(0.0),
+        }
+    }
+
+    /// Folds one document's resolved expression value into the running state.
+    fn update(&mut self, value: &Value) {
+        match self {
+            Accumulator::Sum(total) => *total += value.as_f64().unwrap_or(0.0),
+            Accumulator::Avg { sum, count } => {
+                *sum += value.as_f64().unwrap_or(0.0);
+                *count += 1;
+            }
+            Accumulator::Min(current) => {
+                if current.as_ref().map_or(true, |c| operators::compare_values(value, c) == Ordering::Less) {
+                    *current = Some(value.clone());
+                }
+            }
+            Accumulator::Max(current) => {
+                if current.as_ref().map_or(true, |c| operators::compare_values(value, c) == Ordering::Greater) {
+                    *current = Some(value.clone());
+                }
+            }
+            Accumulator::Count(n) => *n += 1,
+            Accumulator::Push(items) => items.push(value.clone()),
+        }
+    }
+
+    /// Converts the accumulated state into the final JSON value for the output document.
+    fn finalize(self) -> Value {
+        match self {
+            Accumulator::Sum(total) => {
+                serde_json::Number::from_f64(total).map(Value::Number).unwrap_or(Value::Null)
+            }
+            Accumulator::Avg { sum, count } => {
+                if count == 0 {
+                    Value::Null
+                } else {
+                    serde_json::Number::from_f64(sum / count as f64)
+                        .map(Value::Number)
+                        .unwrap_or(Value::Null)
+                }
+            }
+            Accumulator::Min(v) => v.unwrap_or(Value::Null),
+            Accumulator::Max(v) => v.unwrap_or(Value::Null),
+            Accumulator::Count(n) => Value::Number(n.into()),
+            Accumulator::Push(items) => Value::Array(items),
+        }
+    }
+}
+
+/// Flattens an array field, emitting one output document per element (a shallow clone of the
+/// source document with the field replaced by the individual element).
+///
+/// # Spec Shape
+/// Either a bare field reference (`"$tags"`), or the object form
+/// `{"path": "$tags", "preserveNullAndEmptyArrays": true}`. Documents where the field is
+/// missing, `Null`, not an array, or an empty array are dropped unless
+/// `preserveNullAndEmptyArrays` is set, in which case the source document passes through as-is.
+fn apply_unwind(stream: Vec<Value>, spec: &Value) -> Vec<Value> {
+    let (path, preserve_empty) = match spec {
+        Value::String(s) => (s.clone(), false),
+        Value::Object(obj) => {
+            let path = obj.get("path").and_then(Value::as_str).unwrap_or("").to_string();
+            let preserve = obj
+                .get("preserveNullAndEmptyArrays")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            (path, preserve)
+        }
+        _ => return stream,
+    };
+    let field = path.strip_prefix('$').unwrap_or(path.as_str());
+    if field.is_empty() {
+        return stream;
+    }
+
+    let mut out = Vec::new();
+    for doc in &stream {
+        match doc.get(field) {
+            Some(Value::Array(items)) if !items.is_empty() => {
+                for item in items {
+                    let mut new_doc = doc.clone();
+                    if let Some(obj) = new_doc.as_object_mut() {
+                        obj.insert(field.to_string(), item.clone());
+                    }
+                    out.push(new_doc);
+                }
+            }
+            _ => {
+                if preserve_empty {
+                    out.push(doc.clone());
+                }
+            }
+        }
+    }
+    out
+}
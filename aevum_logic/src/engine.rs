@@ -34,10 +34,13 @@
 //! ## Core Features
 //!
 //! - **Query Evaluation:** Supports both direct equality checks and complex logical operators via the `operators` module.
+//! - **Boolean Composition:** `$and`/`$or`/`$nor`/`$not` are resolved by the `predicate` module,
+//!   shared uniformly across `find`, `count`, `update`, and `delete`.
 //! - **Atomic Mutation:** Performs in-memory document updates with strict immutability on `_id` fields.
 //! - **Schema Validation:** Enforces structural integrity and type safety before write operations.
 
 use crate::operators;
+use crate::predicate;
 use serde_json::{Map, Value};
 use std::cmp::Ordering;
 
@@ -47,12 +50,15 @@ use std::cmp::Ordering;
 
 /// Evaluates whether a single document satisfies a given query predicate.
 ///
-/// This function serves as the primary filter mechanism. It supports short-circuiting logic
-/// to maximize performance during table scans.
+/// This function serves as the primary filter mechanism, shared uniformly by `find`, `count`,
+/// `update`, and `delete`. It delegates to the `predicate` module, which resolves boolean
+/// composition (`$and`/`$or`/`$nor`/field-level `$not`) before falling through to the
+/// `operators` module for leaf-level comparisons (`$gt`, `$in`, `$regex`, etc.).
 ///
 /// # Matching Modes
 /// 1. **Direct Equality:** `{"role": "admin"}` checks for exact value equivalence.
 /// 2. **Operator Logic:** `{"age": {"$gt": 18}}` delegates evaluation to the `operators` module.
+/// 3. **Logical Composition:** `{"$or": [...]}` etc. — see `predicate::matches`.
 ///
 /// # Arguments
 /// * `doc` - The generic JSON document to evaluate.
@@ -62,28 +68,7 @@ use std::cmp::Ordering;
 /// * `true` - If the document satisfies **all** conditions in the query (Implicit AND).
 /// * `false` - If any condition fails.
 fn matches_query(doc: &Value, query: &Value) -> bool {
-    if let Some(q_obj) = query.as_object() {
-        for (key, q_val) in q_obj {
-            let doc_val = &doc[key];
-
-            // Determine if the query value represents an operator object (e.g., { "$gt": 10 })
-            // or a direct value comparison (e.g., "Alice").
-            if q_val.is_object() {
-                // Safety: unwrap is safe here as we just verified `is_object()`.
-                for (op, target) in q_val.as_object().unwrap() {
-                    // Delegate complex logic (like $gt, $in, $regex) to the specialized operators module.
-                    if !operators::evaluate(op, doc_val, target) {
-                        return false; // Short-circuit on first failure
-                    }
-                }
-            } else if doc_val != q_val {
-                // strict equality check for primitive values.
-                return false;
-            }
-        }
-    }
-    // If the loop completes without returning false, the document matches all criteria.
-    true
+    predicate::matches(doc, query)
 }
 
 /// Transforms a document by selectively including or excluding fields.
@@ -100,7 +85,7 @@ fn matches_query(doc: &Value, query: &Value) -> bool {
 ///
 /// # Returns
 /// A new `Value::Object` containing only the projected dataset.
-fn apply_projection(doc: &Value, projection: &Value) -> Value {
+pub(crate) fn apply_projection(doc: &Value, projection: &Value) -> Value {
     if let (Some(doc_obj), Some(proj_obj)) = (doc.as_object(), projection.as_object()) {
         // Optimization: If projection is empty, return the document as-is (Zero-copy logical equivalent).
         if proj_obj.is_empty() {
@@ -142,26 +127,28 @@ fn apply_projection(doc: &Value, projection: &Value) -> Value {
 
 /// Determines the relative sort order between two generic JSON values.
 ///
-/// This function handles mixed-type comparisons to ensure a stable sort, although
-/// strict schema design should avoid sorting mixed types.
-///
-/// # Comparison Strategy
-/// * **Strings**: Lexicographical order (case-sensitive).
-/// * **Numbers**: Standard numeric ordering (floats and integers).
-/// * **Booleans**: `false` (0) < `true` (1).
-/// * **Null**: Treated as the lowest value.
+/// Delegates to `operators::compare_values`, the same canonical total order (`Null < Bool <
+/// Number < String < Array < Object`) used by the range operators, so a heterogeneous
+/// collection sorts deterministically instead of treating mixed types as equal.
 fn compare_values(a: &Value, b: &Value) -> Ordering {
-    if let (Some(sa), Some(sb)) = (a.as_str(), b.as_str()) {
-        return sa.cmp(sb);
-    }
-    if let (Some(na), Some(nb)) = (a.as_f64(), b.as_f64()) {
-        return na.partial_cmp(&nb).unwrap_or(Ordering::Equal);
-    }
-    if let (Some(ba), Some(bb)) = (a.as_bool(), b.as_bool()) {
-        return ba.cmp(&bb);
+    operators::compare_values(a, b)
+}
+
+/// Parses a single sort-spec entry into a `(descending, case_insensitive)` pair.
+///
+/// A sort entry is either the plain MongoDB-style integer (`1` ascending, `-1` descending), or
+/// an object form — `{"$order": 1, "$collation": "ci"}` — that additionally opts a string field
+/// into case-insensitive (Unicode-folded) ordering. The plain form is equivalent to
+/// `{"$order": <n>}` with no collation.
+pub(crate) fn parse_sort_spec(order: &Value) -> (bool, bool) {
+    match order.as_object() {
+        Some(obj) => {
+            let descending = obj.get("$order").and_then(Value::as_i64) == Some(-1);
+            let case_insensitive = obj.get("$collation").and_then(Value::as_str) == Some("ci");
+            (descending, case_insensitive)
+        }
+        None => (order.as_i64() == Some(-1), false),
     }
-    // Fallback for disparate types or Nulls
-    Ordering::Equal
 }
 
 // ==================================================================================
@@ -261,7 +248,8 @@ pub fn validate(doc_str: &str, schema_str: &str) -> bool {
 /// # Arguments
 /// * `data_str` - The complete dataset (JSON array).
 /// * `query_str` - Filter criteria.
-/// * `sort_str` - Sorting criteria (e.g., `{"age": -1, "name": 1}`).
+/// * `sort_str` - Sorting criteria (e.g., `{"age": -1, "name": 1}`). A field may instead use the
+///   object form `{"$order": 1, "$collation": "ci"}` to sort that field case-insensitively.
 /// * `proj_str` - Field projection.
 /// * `limit` - Max records to return.
 /// * `skip` - Records to bypass.
@@ -301,15 +289,20 @@ pub fn find(
                 for (key, order) in sort_obj {
                     let val_a = a.get(key).unwrap_or(&Value::Null);
                     let val_b = b.get(key).unwrap_or(&Value::Null);
-                    let cmp = compare_values(val_a, val_b);
+                    let (descending, case_insensitive) = parse_sort_spec(order);
+                    let cmp = if case_insensitive {
+                        // Two strings that fold to the same collation key (e.g. "Cafe" and
+                        // "CAFE") still need a deterministic order relative to each other, so
+                        // fall back to the case-sensitive comparison on the original values as
+                        // a stable tie-break rather than treating them as interchangeable.
+                        operators::compare_values_collated(val_a, val_b)
+                            .then_with(|| compare_values(val_a, val_b))
+                    } else {
+                        compare_values(val_a, val_b)
+                    };
 
                     if cmp != Ordering::Equal {
-                        // -1 indicates descending, 1 indicates ascending
-                        return if order.as_i64() == Some(-1) {
-                            cmp.reverse()
-                        } else {
-                            cmp
-                        };
+                        return if descending { cmp.reverse() } else { cmp };
                     }
                 }
                 // Maintain stability if all sort keys are equal
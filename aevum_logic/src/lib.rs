@@ -48,11 +48,61 @@
 //!    * *Status: Ownership transfers back to Rust, which then safely drops the value.*
 
 use libc::{c_char, c_int};
+use serde_json::Value;
 use std::ffi::{CStr, CString};
+use std::ptr;
 
 // Internal modules handling the business logic.
 mod engine;
+mod index;
 mod operators;
+mod predicate;
+
+// ==================================================================================
+//  STRUCTURED ERROR CHANNEL
+// ==================================================================================
+
+/// Stable error codes surfaced across the FFI boundary by the `_ex` entry points.
+///
+/// Unlike the legacy `rust_find` (which signals any failure as a `NULL` return), these codes
+/// let the C++ host distinguish "no documents matched" (`Ok`, empty result) from "your query
+/// was invalid JSON" (`InvalidQueryJson`) from "out of memory" (`OutOfMemory`) programmatically,
+/// rather than by string-sniffing an error message.
+#[repr(C)]
+pub enum AevumErrorCode {
+    Ok = 0,
+    InvalidDataJson = 1,
+    InvalidQueryJson = 2,
+    InvalidSortJson = 3,
+    InvalidProjection = 4,
+    Utf8Error = 5,
+    OutOfMemory = 6,
+}
+
+/// Writes `code`/`message` into the caller's out-parameters, if provided.
+///
+/// Both out-parameters are optional (`NULL` is a no-op) so hosts that don't care about the
+/// structured detail can pass `NULL` for either and just inspect the returned result pointer.
+fn write_error(error_code: *mut c_int, error_msg: *mut *mut c_char, code: AevumErrorCode, message: &str) {
+    unsafe {
+        if !error_code.is_null() {
+            *error_code = code as c_int;
+        }
+        if !error_msg.is_null() {
+            *error_msg = to_c_string(message.to_string());
+        }
+    }
+}
+
+/// Reads a C string parameter, distinguishing "absent" (`NULL`, treated as `"{}"`, not an
+/// error) from "present but not valid UTF-8" (an error, since the bytes can't be JSON-parsed
+/// at all).
+fn read_c_str_checked(ptr: *const c_char) -> Result<String, ()> {
+    if ptr.is_null() {
+        return Ok("{}".to_string());
+    }
+    unsafe { CStr::from_ptr(ptr).to_str().map(str::to_string).map_err(|_| ()) }
+}
 
 // ==================================================================================
 //  HELPER FUNCTIONS (INTERNAL UTILITIES)
@@ -185,6 +235,116 @@ pub extern "C" fn rust_find(
     ))
 }
 
+/// Retrieves documents with filtering, sorting, and pagination, reporting structured errors.
+///
+/// This is the error-aware companion to [`rust_find`]. Where `rust_find` signals *any* failure
+/// (malformed JSON, UTF-8 issues) by silently falling back to empty defaults, this entry point
+/// always returns a valid, freeable result pointer **and** writes a stable [`AevumErrorCode`]
+/// plus a human-readable message to the out-parameters, so the host can branch on failure kind
+/// instead of treating every failure as "no documents matched".
+///
+/// # Arguments
+/// * `data` / `query` / `sort` / `projection` / `limit` / `skip` - Same as [`rust_find`].
+/// * `error_code` - Out-parameter. Written with an [`AevumErrorCode`] variant (as a `c_int`).
+///   Pass `NULL` to ignore.
+/// * `error_msg` - Out-parameter. On failure, written with a heap-allocated, human-readable
+///   message string — free it with [`rust_free_string`]. On success, written with `NULL`.
+///   Pass `NULL` to ignore (the message is simply not allocated).
+///
+/// # Returns
+/// A raw pointer to a C string containing the result JSON array. On error this is always
+/// `"[]"` — the pointer is still valid and must still be freed with [`rust_free_string`].
+///
+/// # Safety
+/// The returned pointer, and any pointer written through `error_msg`, are new allocations.
+/// The caller **must** free both with [`rust_free_string`].
+#[no_mangle]
+pub extern "C" fn rust_find_ex(
+    data: *const c_char,
+    query: *const c_char,
+    sort: *const c_char,
+    projection: *const c_char,
+    limit: c_int,
+    skip: c_int,
+    error_code: *mut c_int,
+    error_msg: *mut *mut c_char,
+) -> *mut c_char {
+    if !error_msg.is_null() {
+        unsafe {
+            *error_msg = ptr::null_mut();
+        }
+    }
+
+    macro_rules! read_or_fail {
+        ($ptr:expr, $field_name:literal) => {
+            match read_c_str_checked($ptr) {
+                Ok(s) => s,
+                Err(()) => {
+                    write_error(
+                        error_code,
+                        error_msg,
+                        AevumErrorCode::Utf8Error,
+                        &format!("`{}` is not valid UTF-8", $field_name),
+                    );
+                    return to_c_string("[]".to_string());
+                }
+            }
+        };
+    }
+
+    let data_str = read_or_fail!(data, "data");
+    let query_str = read_or_fail!(query, "query");
+    let sort_str = read_or_fail!(sort, "sort");
+    let proj_str = read_or_fail!(projection, "projection");
+
+    macro_rules! validate_json {
+        ($field_str:expr, $field_name:literal, $code:expr) => {
+            if let Err(e) = serde_json::from_str::<Value>(&$field_str) {
+                write_error(
+                    error_code,
+                    error_msg,
+                    $code,
+                    &format!("`{}` is not valid JSON: {}", $field_name, e),
+                );
+                return to_c_string("[]".to_string());
+            }
+        };
+    }
+
+    validate_json!(data_str, "data", AevumErrorCode::InvalidDataJson);
+    validate_json!(query_str, "query", AevumErrorCode::InvalidQueryJson);
+    validate_json!(sort_str, "sort", AevumErrorCode::InvalidSortJson);
+    validate_json!(proj_str, "projection", AevumErrorCode::InvalidProjection);
+
+    let l = if limit < 0 { 0 } else { limit as usize };
+    let s = if skip < 0 { 0 } else { skip as usize };
+
+    let result = engine::find(&data_str, &query_str, &sort_str, &proj_str, l, s);
+
+    match CString::new(result) {
+        Ok(c_str) => {
+            // Success: leave `error_msg` as the `NULL` it was initialized to above.
+            if !error_code.is_null() {
+                unsafe {
+                    *error_code = AevumErrorCode::Ok as c_int;
+                }
+            }
+            c_str.into_raw()
+        }
+        Err(_) => {
+            // The engine never emits a result containing an interior NUL today, but if it
+            // ever does, report it as an allocation-adjacent failure rather than panicking.
+            write_error(
+                error_code,
+                error_msg,
+                AevumErrorCode::OutOfMemory,
+                "failed to allocate a NUL-terminated result string",
+            );
+            to_c_string("[]".to_string())
+        }
+    }
+}
+
 /// Modifies documents in the dataset that match the selection criteria.
 ///
 /// Supports atomic operators like `$set`, `$unset`, `$inc`, `$push`, and `$pull`.
@@ -24,21 +24,38 @@
 //!
 //! | Operator | Description | Implementation Logic |
 //! |----------|-------------|----------------------|
-//! | `$eq` | Equality | Strict structural equality (`val == target`) |
-//! | `$ne` | Not Equal | Strict structural inequality (`val != target`) |
-//! | `$gt` | Greater Than | Numeric comparison (`val > target`) |
-//! | `$lt` | Less Than | Numeric comparison (`val < target`) |
-//! | `$gte` | Greater Than or Equal | Numeric comparison (`val >= target`) |
-//! | `$lte` | Less Than or Equal | Numeric comparison (`val <= target`) |
+//! | `$eq` | Equality | Structural equality; strings compare NFC-normalized (see [`evaluate_eq`]) |
+//! | `$ne` | Not Equal | Negation of `$eq` |
+//! | `$gt` | Greater Than | Numeric or string comparison (`val > target`) |
+//! | `$lt` | Less Than | Numeric or string comparison (`val < target`) |
+//! | `$gte` | Greater Than or Equal | Numeric or string comparison (`val >= target`) |
+//! | `$lte` | Less Than or Equal | Numeric or string comparison (`val <= target`) |
+//! | `$regex` | Pattern Match | Cached-compiled regex match against a string field (see [`evaluate_regex`]) |
+//! | `$in` | Set Membership | `true` if the field structurally equals any element of the target array |
+//! | `$nin` | Set Exclusion | `true` if the field structurally equals no element of the target array |
+//! | `$all` | Superset Check | `true` if the field array contains every element of the target array |
+//! | `$size` | Array Length | `true` if the field is an array of exactly the target length |
+//! | `$elemMatch` | Nested Match | `true` if at least one array element satisfies the target sub-query |
 //!
 //! ## Type Handling & Safety
 //!
 //! - **Structural Equality:** Uses standard JSON equality rules (e.g., objects match if keys/values are identical).
-//! - **Numeric Unification:** Range operators (`$gt`, etc.) strictly require **Numeric** types.
-//!   AevumDB unifies Integers and Floats into `f64` for comparison. Comparing mismatched types
-//!   (e.g., String vs Number) results in `false` (safe failure) rather than a runtime panic.
+//! - **Total Ordering:** Range operators (`$gt`, etc.) order values using the canonical
+//!   cross-type rank `Null < Bool < Number < String < Array < Object` (see [`compare_values`]),
+//!   so comparisons never silently fail closed even when operands differ in type.
+//! - **Unicode Collation:** String comparisons (equality and ordering alike) first apply
+//!   Unicode NFC normalization so visually/semantically identical strings built from different
+//!   combining-character sequences compare equal. An opt-in case-insensitive mode additionally
+//!   folds case using full Unicode case mapping rather than ASCII-only lowercasing.
+//!   Invariant: normalization never panics on lone surrogates, since a Rust `&str` is already
+//!   guaranteed to be a valid sequence of Unicode scalar values (surrogates can't occur).
 
+use regex::{Regex, RegexBuilder};
 use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use unicode_normalization::UnicodeNormalization;
 
 // ==================================================================================
 //  PUBLIC API
@@ -56,25 +73,60 @@ use serde_json::Value;
 ///
 /// # Returns
 /// * `true` - If the condition is met.
-/// * `false` - If the condition is not met, types are incompatible, or the operator is unknown.
+/// * `false` - If the condition is not met, or the operator is unknown.
 ///
-/// # Behavior on Type Mismatch
-/// If an operator requires numeric context (like `$gt`) but receives non-numeric types
-/// (like Strings), it returns `false` by default. This ensures the query engine remains
-/// robust against dirty data.
+/// Range operators (`$gt`/`$lt`/`$gte`/`$lte`) never fail closed on a type mismatch: they order
+/// `field_val`/`target_val` via [`compare_values`]'s cross-type total order (see the module-level
+/// "Total Ordering" doc above), so e.g. a string field compared against a numeric target still
+/// produces a well-defined `true`/`false` rather than always `false`.
 pub fn evaluate(op: &str, field_val: &Value, target_val: &Value) -> bool {
     match op {
         // --- Equality Operators ---
-        // These operate on all JSON types (Strings, Numbers, Objects, Arrays) via structural equality.
-        "$eq" => field_val == target_val,
-        "$ne" => field_val != target_val,
+        // Non-string types still compare via structural equality; strings additionally get
+        // NFC-normalized comparison (see `evaluate_eq`). Case-insensitive matching is an
+        // opt-in sibling (`$ci`) handled by the caller (`predicate::matches_field`), since
+        // `evaluate`'s 3-argument shape has no way to see a sibling key on its own.
+        "$eq" => evaluate_eq(field_val, target_val, false),
+        "$ne" => !evaluate_eq(field_val, target_val, false),
 
-        // --- Numeric Comparison Operators ---
-        // These strictly require both operands to be coercible to f64.
-        "$gt" => compare_f64(field_val, target_val, |a, b| a > b),
-        "$lt" => compare_f64(field_val, target_val, |a, b| a < b),
-        "$gte" => compare_f64(field_val, target_val, |a, b| a >= b),
-        "$lte" => compare_f64(field_val, target_val, |a, b| a <= b),
+        // --- Range Comparison Operators ---
+        // `compare_values` provides a total order across every JSON type, so these never fail
+        // closed purely because of a type mismatch (e.g. Number vs String).
+        "$gt" => compare_values(field_val, target_val) == Ordering::Greater,
+        "$lt" => compare_values(field_val, target_val) == Ordering::Less,
+        "$gte" => compare_values(field_val, target_val) != Ordering::Less,
+        "$lte" => compare_values(field_val, target_val) != Ordering::Greater,
+
+        // --- Pattern Matching Operator ---
+        "$regex" => evaluate_regex(field_val, target_val, None),
+        // `$options` is a modifier consumed alongside a sibling `$regex` key by the caller
+        // (see `engine::matches_query`). Evaluated in isolation it is a no-op.
+        "$options" => true,
+
+        // --- Set-Membership Operators ---
+        // These require `target_val` to describe a set or array shape; the field itself may
+        // be a scalar (`$in`/`$nin`) or an array (`$all`/`$size`/`$elemMatch`).
+        // `$in`/`$nin` reuse `$eq`'s structural-equality rules (including NFC-normalized
+        // string comparison), so a `$in` membership check behaves like a series of `$eq`s.
+        "$in" => target_val
+            .as_array()
+            .map_or(false, |set| set.iter().any(|v| evaluate_eq(field_val, v, false))),
+        "$nin" => target_val
+            .as_array()
+            .map_or(false, |set| !set.iter().any(|v| evaluate_eq(field_val, v, false))),
+        "$all" => match (field_val.as_array(), target_val.as_array()) {
+            (Some(field_arr), Some(target_arr)) => {
+                target_arr.iter().all(|t| field_arr.iter().any(|f| evaluate_eq(f, t, false)))
+            }
+            _ => false,
+        },
+        "$size" => match (field_val.as_array(), target_val.as_u64()) {
+            (Some(field_arr), Some(n)) => field_arr.len() as u64 == n,
+            _ => false,
+        },
+        "$elemMatch" => field_val
+            .as_array()
+            .map_or(false, |field_arr| field_arr.iter().any(|elem| element_matches(elem, target_val))),
 
         // --- Fallback ---
         // Unknown operators are treated as "no match" to prevent undefined behavior.
@@ -82,41 +134,268 @@ pub fn evaluate(op: &str, field_val: &Value, target_val: &Value) -> bool {
     }
 }
 
+/// Tests two JSON values for equality, giving strings Unicode-correct treatment.
+///
+/// Non-string values (including arrays/objects, which may themselves nest strings) fall back
+/// to plain structural equality — NFC normalization only changes the comparison outcome for
+/// `Value::String`, so recursing into every nested string would be surprising without a
+/// compelling use case; this matches the scope of the equality operators today.
+///
+/// # Arguments
+/// * `case_insensitive` - When `true`, both strings are additionally lowercased (via
+///   `char::to_lowercase`'s full Unicode lowercase mapping, not ASCII-only lowercasing) after
+///   normalization.
+pub fn evaluate_eq(field_val: &Value, target_val: &Value, case_insensitive: bool) -> bool {
+    match (field_val.as_str(), target_val.as_str()) {
+        (Some(a), Some(b)) => compare_strings(a, b, case_insensitive) == Ordering::Equal,
+        _ => field_val == target_val,
+    }
+}
+
+/// Matches a document field against a regular expression.
+///
+/// The compiled pattern is cached (see [`compiled_regex`]) rather than rebuilt on every call, so
+/// a `$regex` predicate pays the compilation cost once per distinct pattern/flags pair instead
+/// of once per document in the scan. Non-string fields always fail to match, consistent with
+/// the safe-failure contract used throughout this module.
+///
+/// # Arguments
+/// * `field_val` - The value found in the document. Must be a `Value::String` to match.
+/// * `pattern_val` - The `$regex` target. Must be a `Value::String` containing the pattern.
+/// * `options` - The optional `$options` sibling (e.g. `"i"` for case-insensitive, `"m"` for
+///   multiline). Unrecognized flag characters are ignored.
+///
+/// # Returns
+/// `false` if the field isn't a string, the pattern isn't a string, or the pattern fails to
+/// compile (treated as a safe failure rather than propagating a panic).
+pub fn evaluate_regex(field_val: &Value, pattern_val: &Value, options: Option<&Value>) -> bool {
+    let (Some(field_str), Some(pattern)) = (field_val.as_str(), pattern_val.as_str()) else {
+        return false;
+    };
+
+    let flags = options.and_then(Value::as_str).unwrap_or("");
+
+    match compiled_regex(pattern, flags) {
+        Some(re) => re.is_match(field_str),
+        // Malformed pattern: fail safe instead of propagating the error through `evaluate`.
+        None => false,
+    }
+}
+
+/// Upper bound on the number of distinct (pattern, flags) pairs [`compiled_regex`] will cache
+/// before evicting. Bounds worst-case memory for hosts that build `$regex` patterns from
+/// variable user input (free-text search, prefix filters) rather than a small fixed repertoire,
+/// where the set of distinct patterns issued over the process lifetime isn't actually bounded.
+const MAX_CACHED_PATTERNS: usize = 1024;
+
+/// Compiles (pattern, flags) into a `Regex`, reusing a cached compilation when one already
+/// exists for that exact pair.
+///
+/// `evaluate_regex` runs once per document in a scan but the pattern/flags come from the query,
+/// which is fixed for the whole scan — recompiling per row would be an easily-avoidable O(n)
+/// regex-compile cost on every `$regex` query. `Regex` is internally reference-counted, so the
+/// cached clone returned here is cheap.
+///
+/// The cache is process-wide and capped at [`MAX_CACHED_PATTERNS`] entries: once full, it's
+/// cleared before inserting the new pattern. This is a blunt eviction policy (drops everything,
+/// not just the least-recently-used entry), but it's simple, needs no extra bookkeeping per
+/// lookup, and is enough to keep memory bounded against an unbounded stream of distinct
+/// patterns — a proper LRU would only matter if hosts alternate between more than
+/// `MAX_CACHED_PATTERNS` hot patterns, which isn't the scenario this guards against.
+fn compiled_regex(pattern: &str, flags: &str) -> Option<Regex> {
+    static CACHE: OnceLock<Mutex<HashMap<(String, String), Regex>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+
+    let key = (pattern.to_string(), flags.to_string());
+    if let Some(re) = cache.lock().unwrap().get(&key) {
+        return Some(re.clone());
+    }
+
+    let compiled = RegexBuilder::new(pattern)
+        .case_insensitive(flags.contains('i'))
+        .multi_line(flags.contains('m'))
+        .dot_matches_new_line(flags.contains('s'))
+        .build()
+        .ok()?;
+
+    let mut cache = cache.lock().unwrap();
+    if cache.len() >= MAX_CACHED_PATTERNS {
+        cache.clear();
+    }
+    cache.insert(key, compiled.clone());
+    Some(compiled)
+}
+
+/// Tests a single array element against the sub-query given to `$elemMatch`.
+///
+/// The sub-query uses the same operator grammar as a top-level query object, but each key is
+/// interpreted relative to the *element itself* rather than a parent document:
+///
+/// * A key starting with `$` (e.g. `{"$gt": 5}`) is an operator applied directly to the
+///   element — this is the shape used for arrays of scalars (`"tags": [1, 2, 3]`).
+/// * Any other key is treated as a field name and looked up on the element, which must then be
+///   an object — this is the shape used for arrays of embedded documents.
+///
+/// All keys in the sub-query must match (implicit AND), mirroring `engine::matches_query`.
+fn element_matches(elem: &Value, sub_query: &Value) -> bool {
+    let Some(sub_obj) = sub_query.as_object() else {
+        // A non-object sub-query (e.g. a bare scalar) degrades to direct equality.
+        return evaluate_eq(elem, sub_query, false);
+    };
+
+    for (key, target) in sub_obj {
+        if let Some(op) = key.strip_prefix('$') {
+            if op == "regex" {
+                if !evaluate_regex(elem, target, sub_obj.get("$options")) {
+                    return false;
+                }
+                continue;
+            }
+            if op == "options" {
+                continue;
+            }
+            if !evaluate(key, elem, target) {
+                return false;
+            }
+        } else {
+            let field_val = elem.get(key).unwrap_or(&Value::Null);
+            if target.is_object() {
+                for (op, nested_target) in target.as_object().unwrap() {
+                    if !evaluate(op, field_val, nested_target) {
+                        return false;
+                    }
+                }
+            } else if !evaluate_eq(field_val, target, false) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
 // ==================================================================================
 //  PRIVATE HELPERS
 // ==================================================================================
 
-/// Safely performs a numeric comparison between two JSON values.
+/// Assigns each JSON value a rank in the canonical cross-type total order, mirroring the
+/// comparison semantics MongoDB's BSON type ordering uses: `Null < Bool < Number < String <
+/// Array < Object`.
+#[inline]
+fn type_rank(v: &Value) -> u8 {
+    match v {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// Compares two JSON values under a single, deterministic total order.
 ///
-/// Since JSON differentiates between Integers (`i64`/`u64`) and Floats (`f64`),
-/// this helper unifies them by casting both sides to `f64` before comparing.
-/// This ensures that `10` (Integer) is correctly identified as equal to `10.0` (Float).
+/// This is the single entry point used by both the range operators (`$gt`, `$lt`, `$gte`,
+/// `$lte`) and the sort stage in `engine::find`. Values of the same JSON type compare using
+/// type-appropriate semantics; values of different types compare by [`type_rank`], so the
+/// order is total — it never falls back to "no match" purely because of a type mismatch.
 ///
-/// # Performance Note
-/// This function is marked `#[inline]` to allow the compiler to optimize away the
-/// function call overhead during tight loops (e.g., table scans).
+/// # Same-Type Semantics
+/// * **Number / Number** — unified to `f64` (so `10` compares equal-order to `10.0`); `NaN`
+///   compares as [`Ordering::Equal`] to avoid violating the total order's transitivity.
+/// * **String / String** — compared by Unicode scalar value (see [`compare_strings`]).
+/// * **Bool / Bool** — `false` orders before `true`.
+/// * **Null / Null** — always equal.
+/// * **Array / Array** — element-wise, then by length (so a prefix sorts before its extension).
+/// * **Object / Object** — compared as sorted `(key, value)` pairs, entry by entry.
+pub(crate) fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Bool(ba), Value::Bool(bb)) => ba.cmp(bb),
+        (Value::Number(_), Value::Number(_)) => {
+            // `as_f64` cannot fail here since both sides are confirmed `Value::Number`.
+            a.as_f64()
+                .unwrap()
+                .partial_cmp(&b.as_f64().unwrap())
+                .unwrap_or(Ordering::Equal)
+        }
+        (Value::String(sa), Value::String(sb)) => compare_strings(sa, sb, false),
+        (Value::Array(arr_a), Value::Array(arr_b)) => {
+            for (elem_a, elem_b) in arr_a.iter().zip(arr_b.iter()) {
+                let cmp = compare_values(elem_a, elem_b);
+                if cmp != Ordering::Equal {
+                    return cmp;
+                }
+            }
+            arr_a.len().cmp(&arr_b.len())
+        }
+        (Value::Object(obj_a), Value::Object(obj_b)) => {
+            let mut entries_a: Vec<(&String, &Value)> = obj_a.iter().collect();
+            let mut entries_b: Vec<(&String, &Value)> = obj_b.iter().collect();
+            entries_a.sort_by(|x, y| x.0.cmp(y.0));
+            entries_b.sort_by(|x, y| x.0.cmp(y.0));
+
+            for (entry_a, entry_b) in entries_a.iter().zip(entries_b.iter()) {
+                let key_cmp = entry_a.0.cmp(entry_b.0);
+                if key_cmp != Ordering::Equal {
+                    return key_cmp;
+                }
+                let val_cmp = compare_values(entry_a.1, entry_b.1);
+                if val_cmp != Ordering::Equal {
+                    return val_cmp;
+                }
+            }
+            entries_a.len().cmp(&entries_b.len())
+        }
+        // Different JSON types: fall back to the canonical rank order.
+        _ => type_rank(a).cmp(&type_rank(b)),
+    }
+}
+
+/// Compares two JSON values the same way [`compare_values`] does, except that `String`/`String`
+/// pairs are compared case-insensitively (after NFC normalization) instead of case-sensitively.
+///
+/// This backs the `{"$order": 1, "$collation": "ci"}` sort-spec form in `engine::find`. It is a
+/// thin variant rather than a parameter added to `compare_values` itself, since every other
+/// caller (the range operators, the default sort) wants the case-sensitive behavior and a bool
+/// threaded through the whole recursive match would obscure those call sites for no benefit.
+pub(crate) fn compare_values_collated(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::String(sa), Value::String(sb)) => compare_strings(sa, sb, true),
+        _ => compare_values(a, b),
+    }
+}
+
+/// Compares two strings by Unicode scalar value (codepoint order), not raw UTF-8 byte order.
 ///
-/// # Logic
-/// 1. Attempt to cast `a` (field value) to `f64`.
-/// 2. Attempt to cast `b` (target value) to `f64`.
-/// 3. If **both** succeed, execute the comparison closure `op`.
-/// 4. If **either** fails (e.g., comparing a String to a Number), return `false`.
+/// Both strings are first normalized to NFC, so visually/semantically identical strings built
+/// from different combining-character sequences (e.g. precomposed `"é"` vs. `"e"` + combining
+/// acute accent) compare equal. Each `char` yielded over the normalized sequence is already
+/// guaranteed to be a single Unicode scalar value (a codepoint in `0x0000..=0xD7FF` or
+/// `0xE000..=0x10FFFF` — surrogates are never valid standalone scalar values in Rust), so
+/// iterating and comparing `char`-by-`char` gives consistent codepoint ordering across UTF-8
+/// inputs.
 ///
 /// # Arguments
-/// * `a` - The first value.
-/// * `b` - The second value.
-/// * `op` - A closure defining the comparison strategy.
-#[inline]
-fn compare_f64<F>(a: &Value, b: &Value, op: F) -> bool
-where
-    F: Fn(f64, f64) -> bool,
-{
-    // serde_json::Value::as_f64() handles both integer and float variants automatically,
-    // providing a unified numeric interface.
-    if let (Some(val_a), Some(val_b)) = (a.as_f64(), b.as_f64()) {
-        op(val_a, val_b)
+/// * `a` / `b` - The strings to compare.
+/// * `case_insensitive` - When `true`, each scalar value is additionally lowercased (via
+///   `char::to_lowercase`'s full Unicode lowercase mapping, not ASCII-only lowercasing) after
+///   normalization.
+pub(crate) fn compare_strings(a: &str, b: &str, case_insensitive: bool) -> Ordering {
+    let (na, nb) = (normalize_nfc(a), normalize_nfc(b));
+    if case_insensitive {
+        na.chars()
+            .flat_map(char::to_lowercase)
+            .cmp(nb.chars().flat_map(char::to_lowercase))
     } else {
-        // Fail safe: Non-numeric types cannot participate in numeric range comparisons.
-        false
+        na.chars().cmp(nb.chars())
     }
 }
+
+/// Normalizes a string to Unicode Normalization Form C (NFC), composing combining-character
+/// sequences into their precomposed equivalents wherever one exists.
+///
+/// This never panics: a Rust `&str` is already guaranteed to be a valid sequence of Unicode
+/// scalar values, so lone surrogates (which would make normalization ill-defined) can't occur.
+fn normalize_nfc(s: &str) -> String {
+    s.nfc().collect()
+}
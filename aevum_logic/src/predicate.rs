@@ -0,0 +1,147 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+//! # AevumDB Predicate Tree
+//!
+//! This module is the logical-composition layer sitting above the operator ALU
+//! (`operators::evaluate`). A flat query object is an implicit AND of field constraints, but
+//! real queries need boolean composition across those constraints — `$and`, `$or`, `$nor` at
+//! the top level, and field-scoped `$not` negating a nested operator block.
+//!
+//! `engine::find`, `engine::count`, `engine::update`, and `engine::delete` all route through
+//! [`matches`] uniformly, so every read/write path gets the same boolean-composition support
+//! rather than reimplementing flat AND-of-equalities independently.
+//!
+//! Equality here (direct-value fields and `$eq`/`$ne`) always goes through
+//! `operators::evaluate_eq`, so strings get Unicode NFC-normalized comparison by default. An
+//! opt-in `$ci` sibling modifier (alongside `$eq`/`$ne`, same shape as `$regex`'s `$options`)
+//! additionally folds case.
+
+use crate::operators;
+use serde_json::Value;
+
+// ==================================================================================
+//  PUBLIC API
+// ==================================================================================
+
+/// Evaluates whether a document satisfies a query, recursively resolving `$and`/`$or`/`$nor`.
+///
+/// # Matching Modes
+/// * **Logical Composition:** `$and`/`$or` (array of sub-queries, evaluated recursively) and
+///   `$nor` (array of sub-queries, none of which may match).
+/// * **Field Constraint:** Any other key is a field name, matched via direct equality or a
+///   nested operator block (see [`matches_field`]).
+///
+/// All keys in a query object combine with an implicit AND, short-circuiting on first failure.
+pub fn matches(doc: &Value, query: &Value) -> bool {
+    let Some(obj) = query.as_object() else {
+        return true;
+    };
+
+    for (key, value) in obj {
+        let satisfied = match key.as_str() {
+            "$and" => eval_subqueries(doc, value, Quantifier::All),
+            "$or" => eval_subqueries(doc, value, Quantifier::Any),
+            // `$nor` negates `eval_subqueries`, so it can't reuse that function's "malformed
+            // value fails closed" contract directly: negating its `false` would turn a
+            // malformed `$nor` into an auto-satisfied `true`. Require a real array up front and
+            // fail the whole predicate (not just this key) when it isn't one.
+            "$nor" => value.as_array().is_some() && !eval_subqueries(doc, value, Quantifier::Any),
+            _ => matches_field(doc, key, value),
+        };
+        if !satisfied {
+            return false;
+        }
+    }
+    true
+}
+
+// ==================================================================================
+//  PRIVATE HELPERS
+// ==================================================================================
+
+/// Whether a group of sub-queries must `All` match (`$and`) or just `Any` one (`$or`/`$nor`).
+enum Quantifier {
+    All,
+    Any,
+}
+
+/// Recursively evaluates an array of sub-queries against `doc`, per `quantifier`.
+///
+/// A malformed `$and`/`$or`/`$nor` value (not a JSON array) is a safe failure: it never
+/// matches, the same fail-closed contract `operators::evaluate` uses for unknown operators.
+fn eval_subqueries(doc: &Value, value: &Value, quantifier: Quantifier) -> bool {
+    let Some(sub_queries) = value.as_array() else {
+        return false;
+    };
+
+    match quantifier {
+        Quantifier::All => sub_queries.iter().all(|sub| matches(doc, sub)),
+        Quantifier::Any => sub_queries.iter().any(|sub| matches(doc, sub)),
+    }
+}
+
+/// Evaluates a single field constraint, which is either a direct value (strict equality) or an
+/// operator block (e.g. `{"$gt": 10}`), optionally containing a field-level `$not`.
+fn matches_field(doc: &Value, key: &str, q_val: &Value) -> bool {
+    let doc_val = &doc[key];
+
+    let Some(op_block) = q_val.as_object() else {
+        // Direct value comparison, e.g. `{"role": "admin"}`. Routed through `evaluate_eq`
+        // (rather than raw `==`) so strings still get NFC-normalized comparison here.
+        return operators::evaluate_eq(doc_val, q_val, false);
+    };
+
+    // `$ci` is a sibling modifier of `$eq`/`$ne`/`$in`/`$nin`, analogous to `$regex`'s
+    // `$options`: it carries no meaning evaluated on its own, only alongside one of those keys.
+    let case_insensitive = op_block.get("$ci").and_then(Value::as_bool).unwrap_or(false);
+
+    for (op, target) in op_block {
+        let satisfied = match op.as_str() {
+            "$options" | "$ci" => true,
+            "$regex" => operators::evaluate_regex(doc_val, target, op_block.get("$options")),
+            "$eq" => operators::evaluate_eq(doc_val, target, case_insensitive),
+            "$ne" => !operators::evaluate_eq(doc_val, target, case_insensitive),
+            "$not" => !matches_operator_block(doc_val, target),
+            _ => operators::evaluate(op, doc_val, target),
+        };
+        if !satisfied {
+            return false;
+        }
+    }
+    true
+}
+
+/// Evaluates (without negating) an operator block for use inside `$not`.
+///
+/// `target` is itself a nested operator block, e.g. `{"$not": {"$gt": 5}}` negates the result
+/// of evaluating `{"$gt": 5}`. A bare value (not an object) degrades to direct equality, so
+/// `{"$not": "admin"}` matches any value that isn't structurally equal to `"admin"`.
+fn matches_operator_block(doc_val: &Value, target: &Value) -> bool {
+    let Some(op_block) = target.as_object() else {
+        return operators::evaluate_eq(doc_val, target, false);
+    };
+
+    let case_insensitive = op_block.get("$ci").and_then(Value::as_bool).unwrap_or(false);
+
+    op_block.iter().all(|(op, sub_target)| match op.as_str() {
+        "$options" | "$ci" => true,
+        "$regex" => operators::evaluate_regex(doc_val, sub_target, op_block.get("$options")),
+        "$eq" => operators::evaluate_eq(doc_val, sub_target, case_insensitive),
+        "$ne" => !operators::evaluate_eq(doc_val, sub_target, case_insensitive),
+        _ => operators::evaluate(op, doc_val, sub_target),
+    })
+}
@@ -0,0 +1,142 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    fn run_aggregate(data: &str, pipeline: &str) -> String {
+        let data_ptr = allocate_c_string(data);
+        let pipeline_ptr = allocate_c_string(pipeline);
+
+        let res_ptr = aevum_logic::rust_aggregate(data_ptr, pipeline_ptr);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap().to_string();
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(pipeline_ptr);
+        }
+
+        res_str
+    }
+
+    const DATASET: &str = r#"[
+        {"id": 1, "role": "admin", "age": 40, "tags": ["a", "b"]},
+        {"id": 2, "role": "guest", "age": 20, "tags": []},
+        {"id": 3, "role": "editor", "age": 30, "tags": ["c"]},
+        {"id": 4, "role": "admin", "age": 50, "tags": ["d"]}
+    ]"#;
+
+    // ==================================================================================
+    //  INTEGRATION TESTS
+    // ==================================================================================
+
+    #[test]
+    fn test_ffi_aggregate_match_and_sort() {
+        let res = run_aggregate(
+            DATASET,
+            r#"[{"$match": {"age": {"$gt": 25}}}, {"$sort": {"age": -1}}]"#,
+        );
+        let id4_pos = res.find(r#""id":4"#).unwrap();
+        let id1_pos = res.find(r#""id":1"#).unwrap();
+        let id3_pos = res.find(r#""id":3"#).unwrap();
+        assert!(id4_pos < id1_pos && id1_pos < id3_pos);
+        assert!(!res.contains(r#""id":2"#));
+    }
+
+    #[test]
+    fn test_ffi_aggregate_project() {
+        let res = run_aggregate(DATASET, r#"[{"$match": {"id": 1}}, {"$project": {"role": 1}}]"#);
+        assert!(res.contains(r#""role":"admin""#));
+        assert!(!res.contains("\"age\""));
+    }
+
+    #[test]
+    fn test_ffi_aggregate_limit_and_skip() {
+        let res = run_aggregate(DATASET, r#"[{"$sort": {"id": 1}}, {"$skip": 1}, {"$limit": 1}]"#);
+        assert!(res.contains(r#""id":2"#));
+        assert!(!res.contains(r#""id":1"#) && !res.contains(r#""id":3"#) && !res.contains(r#""id":4"#));
+    }
+
+    #[test]
+    fn test_ffi_aggregate_group_sum_avg_count() {
+        let res = run_aggregate(
+            DATASET,
+            r#"[{"$group": {"_id": "$role", "total": {"$sum": 1}, "avgAge": {"$avg": "$age"}}}]"#,
+        );
+        assert!(res.contains(r#""_id":"admin""#));
+        assert!(res.contains(r#""total":2"#));
+        assert!(res.contains(r#""avgAge":45.0"#));
+        assert!(res.contains(r#""_id":"guest""#));
+        assert!(res.contains(r#""_id":"editor""#));
+    }
+
+    #[test]
+    fn test_ffi_aggregate_group_min_max_push() {
+        let res = run_aggregate(
+            DATASET,
+            r#"[{"$match": {"role": "admin"}}, {"$group": {"_id": null, "minAge": {"$min": "$age"}, "maxAge": {"$max": "$age"}, "ages": {"$push": "$age"}}}]"#,
+        );
+        assert!(res.contains(r#""minAge":40"#));
+        assert!(res.contains(r#""maxAge":50"#));
+        assert!(res.contains(r#""ages":[40,50]"#));
+    }
+
+    #[test]
+    fn test_ffi_aggregate_unwind_drops_empty_arrays_by_default() {
+        let res = run_aggregate(DATASET, r#"[{"$unwind": "$tags"}]"#);
+        // id 2 has an empty tags array and should be dropped.
+        assert!(!res.contains(r#""id":2"#));
+        assert!(res.contains(r#""tags":"a""#));
+        assert!(res.contains(r#""tags":"b""#));
+        assert!(res.contains(r#""tags":"c""#));
+    }
+
+    #[test]
+    fn test_ffi_aggregate_full_pipeline() {
+        let res = run_aggregate(
+            DATASET,
+            r#"[
+                {"$match": {"age": {"$gte": 20}}},
+                {"$group": {"_id": "$role", "count": {"$sum": 1}}},
+                {"$sort": {"count": -1}}
+            ]"#,
+        );
+        assert!(res.contains(r#""_id":"admin""#));
+        assert!(res.contains(r#""count":2"#));
+    }
+}
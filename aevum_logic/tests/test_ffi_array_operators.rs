@@ -0,0 +1,110 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    fn run_find(data: &str, query: &str) -> String {
+        let data_ptr = allocate_c_string(data);
+        let query_ptr = allocate_c_string(query);
+        let sort_ptr = allocate_c_string("{}");
+        let proj_ptr = allocate_c_string("{}");
+
+        let res_ptr = aevum_logic::rust_find(data_ptr, query_ptr, sort_ptr, proj_ptr, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap().to_string();
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj_ptr);
+        }
+
+        res_str
+    }
+
+    // ==================================================================================
+    //  INTEGRATION TESTS
+    // ==================================================================================
+
+    #[test]
+    fn test_ffi_in_and_nin() {
+        let data = r#"[{"id": 1, "role": "admin"}, {"id": 2, "role": "guest"}, {"id": 3, "role": "editor"}]"#;
+
+        let in_res = run_find(data, r#"{"role": {"$in": ["admin", "editor"]}}"#);
+        assert!(in_res.contains("admin") && in_res.contains("editor"));
+        assert!(!in_res.contains("guest"));
+
+        let nin_res = run_find(data, r#"{"role": {"$nin": ["admin", "editor"]}}"#);
+        assert!(nin_res.contains("guest"));
+        assert!(!nin_res.contains("admin"));
+    }
+
+    #[test]
+    fn test_ffi_all_and_size() {
+        let data = r#"[{"id": 1, "tags": ["rust", "db"]}, {"id": 2, "tags": ["rust"]}]"#;
+
+        let all_res = run_find(data, r#"{"tags": {"$all": ["rust", "db"]}}"#);
+        assert!(all_res.contains(r#""id":1"#));
+        assert!(!all_res.contains(r#""id":2"#));
+
+        let size_res = run_find(data, r#"{"tags": {"$size": 1}}"#);
+        assert!(size_res.contains(r#""id":2"#));
+        assert!(!size_res.contains(r#""id":1"#));
+    }
+
+    #[test]
+    fn test_ffi_elem_match_scalar_and_object() {
+        let scalar_data = r#"[{"id": 1, "scores": [10, 90]}, {"id": 2, "scores": [10, 20]}]"#;
+        let scalar_res = run_find(scalar_data, r#"{"scores": {"$elemMatch": {"$gt": 50}}}"#);
+        assert!(scalar_res.contains(r#""id":1"#));
+        assert!(!scalar_res.contains(r#""id":2"#));
+
+        let obj_data = r#"[
+            {"id": 1, "items": [{"sku": "a", "qty": 5}]},
+            {"id": 2, "items": [{"sku": "b", "qty": 1}]}
+        ]"#;
+        let obj_res = run_find(obj_data, r#"{"items": {"$elemMatch": {"qty": {"$gte": 5}}}}"#);
+        assert!(obj_res.contains(r#""id":1"#));
+        assert!(!obj_res.contains(r#""id":2"#));
+    }
+}
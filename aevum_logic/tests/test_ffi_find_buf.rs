@@ -0,0 +1,139 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::CString;
+    use std::os::raw::c_char;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    /// Reads an `AevumBuf`'s exact byte range into an owned `String`, without relying on a NUL
+    /// terminator (the whole point of the buffer channel under test).
+    fn buf_to_string(buf: &aevum_logic::AevumBuf) -> String {
+        let bytes = unsafe { std::slice::from_raw_parts(buf.data, buf.len) };
+        std::str::from_utf8(bytes).unwrap().to_string()
+    }
+
+    // ==================================================================================
+    //  INTEGRATION TESTS
+    // ==================================================================================
+
+    #[test]
+    fn test_ffi_find_buf_matches_find_for_normal_data() {
+        let data = allocate_c_string(r#"[{"id": 1, "role": "admin"}, {"id": 2, "role": "guest"}]"#);
+        let query = allocate_c_string(r#"{"role": "admin"}"#);
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let buf = aevum_logic::rust_find_buf(data, query, sort, proj, 10, 0);
+        let result = buf_to_string(&buf);
+
+        assert!(result.contains(r#""id":1"#));
+        assert!(!result.contains(r#""id":2"#));
+
+        unsafe {
+            aevum_logic::rust_free_buf(buf);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+
+    #[test]
+    fn test_ffi_find_buf_preserves_full_length_for_large_result() {
+        // A sanity check that `len` describes the whole payload, not a truncated prefix.
+        let docs: Vec<String> = (0..50).map(|i| format!(r#"{{"id": {}}}"#, i)).collect();
+        let data_json = format!("[{}]", docs.join(","));
+
+        let data = allocate_c_string(&data_json);
+        let query = allocate_c_string("{}");
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let buf = aevum_logic::rust_find_buf(data, query, sort, proj, 0, 0);
+        let result = buf_to_string(&buf);
+
+        assert!(buf.len >= data_json.len() / 2, "Expected the buffer to hold the full result set.");
+        assert!(result.contains(r#""id":0"#));
+        assert!(result.contains(r#""id":49"#));
+
+        unsafe {
+            aevum_logic::rust_free_buf(buf);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+
+    #[test]
+    fn test_ffi_find_buf_empty_result_yields_empty_array_bytes() {
+        let data = allocate_c_string(r#"[{"id": 1}]"#);
+        let query = allocate_c_string(r#"{"id": 999}"#);
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let buf = aevum_logic::rust_find_buf(data, query, sort, proj, 10, 0);
+        assert_eq!(buf_to_string(&buf), "[]");
+
+        unsafe {
+            aevum_logic::rust_free_buf(buf);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+}
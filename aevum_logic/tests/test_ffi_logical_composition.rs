@@ -0,0 +1,108 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    fn run_find(data: &str, query: &str) -> String {
+        let data_ptr = allocate_c_string(data);
+        let query_ptr = allocate_c_string(query);
+        let sort_ptr = allocate_c_string("{}");
+        let proj_ptr = allocate_c_string("{}");
+
+        let res_ptr = aevum_logic::rust_find(data_ptr, query_ptr, sort_ptr, proj_ptr, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap().to_string();
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj_ptr);
+        }
+
+        res_str
+    }
+
+    const DATASET: &str = r#"[
+        {"id": 1, "role": "admin", "age": 40},
+        {"id": 2, "role": "guest", "age": 20},
+        {"id": 3, "role": "editor", "age": 30}
+    ]"#;
+
+    #[test]
+    fn test_ffi_and_composition() {
+        let res = run_find(DATASET, r#"{"$and": [{"role": "admin"}, {"age": {"$gt": 30}}]}"#);
+        assert!(res.contains(r#""id":1"#));
+        assert!(!res.contains(r#""id":2"#) && !res.contains(r#""id":3"#));
+    }
+
+    #[test]
+    fn test_ffi_or_composition() {
+        let res = run_find(DATASET, r#"{"$or": [{"role": "guest"}, {"age": {"$gt": 35}}]}"#);
+        assert!(res.contains(r#""id":1"#) && res.contains(r#""id":2"#));
+        assert!(!res.contains(r#""id":3"#));
+    }
+
+    #[test]
+    fn test_ffi_nor_composition() {
+        let res = run_find(DATASET, r#"{"$nor": [{"role": "guest"}, {"role": "admin"}]}"#);
+        assert!(res.contains(r#""id":3"#));
+        assert!(!res.contains(r#""id":1"#) && !res.contains(r#""id":2"#));
+    }
+
+    #[test]
+    fn test_ffi_field_level_not() {
+        let res = run_find(DATASET, r#"{"age": {"$not": {"$gt": 25}}}"#);
+        assert!(res.contains(r#""id":2"#));
+        assert!(!res.contains(r#""id":1"#) && !res.contains(r#""id":3"#));
+    }
+
+    #[test]
+    fn test_ffi_nested_and_or() {
+        let res = run_find(
+            DATASET,
+            r#"{"$and": [{"$or": [{"role": "admin"}, {"role": "editor"}]}, {"age": {"$lt": 35}}]}"#,
+        );
+        assert!(res.contains(r#""id":3"#));
+        assert!(!res.contains(r#""id":1"#) && !res.contains(r#""id":2"#));
+    }
+}
@@ -0,0 +1,119 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    // ==================================================================================
+    //  INTEGRATION TESTS
+    // ==================================================================================
+
+    #[test]
+    fn test_ffi_sort_is_deterministic_across_mixed_types() {
+        // SETUP: a collection where the sort key is sometimes a Number, sometimes a String,
+        // and sometimes Null — a heterogeneous field shape that used to sort non-deterministically.
+        let data = allocate_c_string(
+            r#"[
+            {"id": 1, "val": "ten"},
+            {"id": 2, "val": null},
+            {"id": 3, "val": 5},
+            {"id": 4, "val": true}
+        ]"#,
+        );
+        let query = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+        let sort = allocate_c_string(r#"{ "val": 1 }"#);
+
+        let res_ptr = aevum_logic::rust_find(data, query, sort, proj, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap();
+
+        // Canonical rank order is Null < Bool < Number < String, so the expected ascending
+        // order by `id` is: 2 (null), 4 (true), 3 (5), 1 ("ten").
+        let idx_2 = res_str.find(r#""id":2"#).expect("Missing id 2");
+        let idx_4 = res_str.find(r#""id":4"#).expect("Missing id 4");
+        let idx_3 = res_str.find(r#""id":3"#).expect("Missing id 3");
+        let idx_1 = res_str.find(r#""id":1"#).expect("Missing id 1");
+
+        assert!(idx_2 < idx_4, "Null should sort before Bool");
+        assert!(idx_4 < idx_3, "Bool should sort before Number");
+        assert!(idx_3 < idx_1, "Number should sort before String");
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+
+    #[test]
+    fn test_ffi_range_operator_cross_type_rank() {
+        // A String always outranks a Number in the canonical order, so `$gt` against a
+        // numeric target should match string-valued documents instead of failing closed.
+        let data = allocate_c_string(r#"[{"id": 1, "val": 5}, {"id": 2, "val": "anything"}]"#);
+        let query = allocate_c_string(r#"{"val": {"$gt": 100}}"#);
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let res_ptr = aevum_logic::rust_find(data, query, sort, proj, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap();
+
+        assert!(res_str.contains(r#""id":2"#), "String should rank above Number. Output: {}", res_str);
+        assert!(!res_str.contains(r#""id":1"#));
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    // ==================================================================================
+    //  INTEGRATION TESTS
+    // ==================================================================================
+
+    #[test]
+    fn test_ffi_string_gte_range() {
+        // SETUP: Names that straddle the 'M' boundary alphabetically.
+        let data = allocate_c_string(
+            r#"[{"name": "Ananda"}, {"name": "Maria"}, {"name": "Zoe"}]"#,
+        );
+        let query = allocate_c_string(r#"{"name": {"$gte": "M"}}"#);
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let res_ptr = aevum_logic::rust_find(data, query, sort, proj, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap();
+
+        assert!(
+            res_str.contains("Maria") && res_str.contains("Zoe"),
+            "Range Error: Expected 'Maria' and 'Zoe' to be >= 'M'. Output: {}",
+            res_str
+        );
+        assert!(
+            !res_str.contains("Ananda"),
+            "Range Error: 'Ananda' should be filtered out (< 'M'). Output: {}",
+            res_str
+        );
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+
+    #[test]
+    fn test_ffi_string_number_range_mismatch_is_false() {
+        // A string target against a numeric field must safely fail, not panic.
+        let data = allocate_c_string(r#"[{"age": 30}]"#);
+        let query = allocate_c_string(r#"{"age": {"$gt": "20"}}"#);
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let res_ptr = aevum_logic::rust_find(data, query, sort, proj, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap();
+
+        assert_eq!(
+            res_str, "[]",
+            "Type Mismatch Error: Expected no matches for incompatible types."
+        );
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    // ==================================================================================
+    //  INTEGRATION TESTS
+    // ==================================================================================
+
+    #[test]
+    fn test_ffi_find_ex_reports_invalid_query_json() {
+        let data = allocate_c_string(r#"[{"id": 1}]"#);
+        let query = allocate_c_string("{not valid json"); // malformed
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let mut error_code: i32 = -1;
+        let mut error_msg: *mut c_char = ptr::null_mut();
+
+        let res_ptr = unsafe {
+            aevum_logic::rust_find_ex(data, query, sort, proj, 10, 0, &mut error_code, &mut error_msg)
+        };
+
+        assert!(!res_ptr.is_null(), "rust_find_ex must always return a valid pointer.");
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap();
+        assert_eq!(res_str, "[]");
+
+        assert_eq!(error_code, 2, "Expected InvalidQueryJson error code.");
+        assert!(!error_msg.is_null(), "Expected an error message to be allocated.");
+        let msg_str = unsafe { CStr::from_ptr(error_msg) }.to_str().unwrap();
+        assert!(msg_str.contains("query"));
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(error_msg);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+
+    #[test]
+    fn test_ffi_find_ex_success_path_reports_ok() {
+        let data = allocate_c_string(r#"[{"id": 1}, {"id": 2}]"#);
+        let query = allocate_c_string(r#"{"id": 1}"#);
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let mut error_code: i32 = -1;
+        let mut error_msg: *mut c_char = ptr::null_mut();
+
+        let res_ptr = unsafe {
+            aevum_logic::rust_find_ex(data, query, sort, proj, 10, 0, &mut error_code, &mut error_msg)
+        };
+
+        assert_eq!(error_code, 0, "Expected Ok error code on the success path.");
+        assert!(error_msg.is_null(), "Expected no error message on success.");
+
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap();
+        assert!(res_str.contains(r#""id":1"#));
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+
+    #[test]
+    fn test_ffi_find_ex_tolerates_null_error_out_params() {
+        // Hosts that don't care about structured detail may pass NULL for both.
+        let data = allocate_c_string(r#"[{"id": 1}]"#);
+        let query = allocate_c_string("{}");
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let res_ptr = unsafe {
+            aevum_logic::rust_find_ex(data, query, sort, proj, 10, 0, ptr::null_mut(), ptr::null_mut())
+        };
+
+        assert!(!res_ptr.is_null());
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+}
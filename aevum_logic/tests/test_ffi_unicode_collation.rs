@@ -0,0 +1,146 @@
+/*
+ * AEVUMDB COMMUNITY LICENSE
+ * Version 1.0, February 2026
+ *
+ * Copyright (c) 2026 Ananda Firmansyah.
+ * Official Organization: AevumDB (https://github.com/aevumdb)
+ *
+ * This source code is licensed under the AevumDB Community License.
+ * You may not use this file except in compliance with the License.
+ * A copy of the License is located at the root of this repository.
+ *
+ * UNLESS REQUIRED BY APPLICABLE LAW OR AGREED TO IN WRITING, SOFTWARE
+ * DISTRIBUTED UNDER THE LICENSE IS PROVIDED "AS IS", WITHOUT WARRANTY
+ * OF ANY KIND, EITHER EXPRESS OR IMPLIED.
+ */
+
+#[cfg(test)]
+mod tests {
+    use aevum_logic;
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+
+    // ==================================================================================
+    //  TEST HELPERS
+    // ==================================================================================
+
+    /// Allocates a C-compatible string on the heap and returns a raw pointer.
+    ///
+    /// # Memory Safety
+    /// This function transfers ownership of the memory to the caller.
+    /// The caller is strictly responsible for deallocating this memory using
+    /// `aevum_logic::rust_free_string` to prevent memory leaks during testing.
+    fn allocate_c_string(s: &str) -> *mut c_char {
+        CString::new(s).unwrap().into_raw()
+    }
+
+    fn run_find(data: &str, query: &str, sort: &str) -> String {
+        let data_ptr = allocate_c_string(data);
+        let query_ptr = allocate_c_string(query);
+        let sort_ptr = allocate_c_string(sort);
+        let proj_ptr = allocate_c_string("{}");
+
+        let res_ptr = aevum_logic::rust_find(data_ptr, query_ptr, sort_ptr, proj_ptr, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap().to_string();
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj_ptr);
+        }
+
+        res_str
+    }
+
+    // ==================================================================================
+    //  INTEGRATION TESTS
+    // ==================================================================================
+
+    #[test]
+    fn test_ffi_eq_matches_nfc_normalized_equivalent_forms() {
+        // "Cafe\u{301}" (e + combining acute accent) should compare equal to the precomposed
+        // "Café" once both sides are NFC-normalized.
+        let data = allocate_c_string(r#"[{"id": 1, "name": "Café"}, {"id": 2, "name": "Other"}]"#);
+        let query = allocate_c_string(r#"{"name": "Café"}"#);
+        let sort = allocate_c_string("{}");
+        let proj = allocate_c_string("{}");
+
+        let res_ptr = aevum_logic::rust_find(data, query, sort, proj, 10, 0);
+        let res_str = unsafe { CStr::from_ptr(res_ptr) }.to_str().unwrap();
+
+        assert!(res_str.contains(r#""id":1"#), "Expected NFC-normalized equality match.");
+        assert!(!res_str.contains(r#""id":2"#));
+
+        unsafe {
+            aevum_logic::rust_free_string(res_ptr);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(data);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(query);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(sort);
+        }
+        unsafe {
+            aevum_logic::rust_free_string(proj);
+        }
+    }
+
+    #[test]
+    fn test_ffi_eq_with_ci_folds_case() {
+        let res = run_find(
+            r#"[{"id": 1, "name": "ANANDA"}, {"id": 2, "name": "Bayu"}]"#,
+            r#"{"name": {"$eq": "ananda", "$ci": true}}"#,
+            "{}",
+        );
+        assert!(res.contains(r#""id":1"#));
+        assert!(!res.contains(r#""id":2"#));
+    }
+
+    #[test]
+    fn test_ffi_eq_without_ci_is_case_sensitive() {
+        let res = run_find(
+            r#"[{"id": 1, "name": "ANANDA"}]"#,
+            r#"{"name": {"$eq": "ananda"}}"#,
+            "{}",
+        );
+        assert_eq!(res, "[]", "Expected case-sensitive $eq to reject a differently-cased match.");
+    }
+
+    #[test]
+    fn test_ffi_sort_with_ci_collation_is_case_insensitive() {
+        let res = run_find(
+            r#"[{"name": "banana"}, {"name": "Apple"}, {"name": "cherry"}]"#,
+            "{}",
+            r#"{"name": {"$order": 1, "$collation": "ci"}}"#,
+        );
+        let apple_pos = res.find("Apple").unwrap();
+        let banana_pos = res.find("banana").unwrap();
+        let cherry_pos = res.find("cherry").unwrap();
+        assert!(apple_pos < banana_pos && banana_pos < cherry_pos);
+    }
+
+    #[test]
+    fn test_ffi_sort_plain_integer_form_still_works_alongside_collation() {
+        let res = run_find(
+            r#"[{"name": "banana", "age": 20}, {"name": "Apple", "age": 10}]"#,
+            "{}",
+            r#"{"age": -1}"#,
+        );
+        let banana_pos = res.find("banana").unwrap();
+        let apple_pos = res.find("Apple").unwrap();
+        assert!(banana_pos < apple_pos, "Expected descending sort by age to put 20 before 10.");
+    }
+}